@@ -0,0 +1,124 @@
+//! Fixtures shared by the library and binary unit tests.
+
+use super::*;
+
+pub(crate) fn today() -> NaiveDate {
+    reference_date(Tz::Asia__Kolkata, 0)
+}
+
+pub(crate) fn fixture_json() -> String {
+    let today = today().format(DATE_FORMAT).to_string();
+    format!(
+        r#"{{
+            "centers": [
+                {{
+                    "center_id": 1,
+                    "name": "City Hospital",
+                    "address": "1 Main Road",
+                    "pincode": 110001,
+                    "fee_type": "Free",
+                    "lat": 28.6139,
+                    "long": 77.209,
+                    "sessions": [
+                        {{
+                            "date": "{today}",
+                            "available_capacity": 10,
+                            "min_age_limit": 18,
+                            "vaccine": "COVISHIELD",
+                            "available_capacity_dose1": 8,
+                            "available_capacity_dose2": 2,
+                            "slots": ["09:00AM-11:00AM", "11:00AM-01:00PM"]
+                        }},
+                        {{
+                            "date": "{today}",
+                            "available_capacity": 5,
+                            "min_age_limit": 45,
+                            "vaccine": "COVAXIN",
+                            "available_capacity_dose1": 3,
+                            "available_capacity_dose2": 2
+                        }}
+                    ]
+                }},
+                {{
+                    "center_id": 2,
+                    "name": "Community Center",
+                    "address": "2 Side Street",
+                    "pincode": 110002,
+                    "fee_type": "Paid",
+                    "vaccine_fees": [{{"vaccine": "COVISHIELD", "fee": "780"}}],
+                    "lat": 19.076,
+                    "long": 72.8777,
+                    "sessions": [
+                        {{
+                            "date": "{today}",
+                            "available_capacity": 0,
+                            "min_age_limit": 18,
+                            "vaccine": "COVISHIELD",
+                            "available_capacity_dose1": 0,
+                            "available_capacity_dose2": 0
+                        }}
+                    ]
+                }}
+            ]
+        }}"#,
+        today = today
+    )
+}
+
+pub(crate) fn fixture() -> Resp {
+    serde_json::from_str(&fixture_json()).unwrap()
+}
+
+pub(crate) fn mock_api(max_retries: u32) -> CowinApi {
+    CowinApi {
+        client: build_client(None, Duration::from_secs(10), &[], None).unwrap(),
+        base_urls: vec![mockito::server_url()],
+        location_url: mockito::server_url(),
+        user_agents: vec![String::from("test-agent")],
+        next_agent: AtomicUsize::new(0),
+        auth_token: None,
+        retry: RetryPolicy {
+            max_retries,
+            base_ms: 1,
+        },
+        verbose: 0,
+        observer: None,
+    }
+}
+
+pub(crate) fn filters(only_18plus: bool, only_first_dose: bool) -> Filters {
+    Filters {
+        min_age: if only_18plus { Some(18) } else { None },
+        exact_age: None,
+        session_filter: None,
+        only_first_dose,
+        only_second_dose: false,
+        min_capacity: 1,
+        min_capacity_for: HashMap::new(),
+        min_dose1_capacity: 5,
+        min_dose2_capacity: 5,
+        vaccines: vec![],
+        fee_type: None,
+        max_fee: None,
+        start_date: today(),
+        days_ahead: 7,
+        dates: vec![],
+        center_names: vec![],
+        pincodes: vec![],
+        watch_centers: vec![],
+        ignore_centers: vec![],
+        include_full: false,
+        near: None,
+        radius_km: 0.0,
+    }
+}
+
+pub(crate) fn message_format(template: Option<&str>) -> MessageFormat {
+    MessageFormat {
+        booking_url: String::from(BOOKING_URL),
+        template: template.map(String::from),
+        bands: CapacityBands { few: 5, some: 20 },
+        compact: false,
+        timezone: Tz::Asia__Kolkata,
+    }
+}